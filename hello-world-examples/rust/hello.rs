@@ -1,9 +1,16 @@
 /// Hello World in Rust - Idiomatic Implementation
 /// Demonstrates Rust best practices, error handling, and ownership
 
+mod tui;
+
+use std::env;
 use std::error::Error;
 use std::fmt;
+use std::fs;
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::OnceLock;
 
 /// Custom error type for greeting operations
 #[derive(Debug)]
@@ -11,6 +18,8 @@ enum GreetError {
     EmptyName,
     EmptyGreeting,
     IoError(io::Error),
+    MalformedToken(char),
+    MissingEnvVar(String),
 }
 
 impl fmt::Display for GreetError {
@@ -19,6 +28,8 @@ impl fmt::Display for GreetError {
             GreetError::EmptyName => write!(f, "Name cannot be empty"),
             GreetError::EmptyGreeting => write!(f, "Greeting cannot be empty"),
             GreetError::IoError(e) => write!(f, "IO error: {}", e),
+            GreetError::MalformedToken(c) => write!(f, "Malformed template token: \\{}", c),
+            GreetError::MissingEnvVar(var) => write!(f, "Environment variable {} is not set", var),
         }
     }
 }
@@ -31,10 +42,35 @@ impl From<io::Error> for GreetError {
     }
 }
 
+// `uname` output never changes for the lifetime of the process, so each
+// field is shelled out for at most once and cached here.
+static NODENAME: OnceLock<String> = OnceLock::new();
+static SYSNAME: OnceLock<String> = OnceLock::new();
+static RELEASE: OnceLock<String> = OnceLock::new();
+static VERSION: OnceLock<String> = OnceLock::new();
+static MACHINE: OnceLock<String> = OnceLock::new();
+
 /// A struct to hold greeting configuration
+///
+/// `greeting` is a raw template: it may contain `/etc/issue`-style escape
+/// tokens (`\n \s \r \v \m \\`) that are expanded lazily each time
+/// [`Greeter::greet`] is called, against host information queried from
+/// `uname` on first use and cached for the process lifetime. `default_name` is
+/// the recipient substituted when `greet` is called with `None`.
+///
+/// The `mode`/`previous_mode`/`buffer`/`session_source`/`sessions`/`users`
+/// fields back the interactive TUI front-end in [`tui`]; a plain one-shot
+/// caller can ignore them entirely.
 #[derive(Debug, Clone)]
 struct Greeter {
     greeting: String,
+    default_name: String,
+    mode: tui::Mode,
+    previous_mode: tui::Mode,
+    buffer: tui::InputBuffer,
+    session_source: PathBuf,
+    sessions: tui::Menu<String>,
+    users: tui::Menu<String>,
 }
 
 impl Greeter {
@@ -44,24 +80,147 @@ impl Greeter {
         if greeting.is_empty() {
             return Err(GreetError::EmptyGreeting);
         }
-        Ok(Self { greeting })
+        Ok(Self {
+            greeting,
+            default_name: "world".to_string(),
+            ..Default::default()
+        })
     }
-    
-    /// Creates a greeting message
-    fn greet(&self, name: &str) -> Result<String, GreetError> {
-        if name.is_empty() {
-            return Err(GreetError::EmptyName);
+
+    /// Sets the recipient substituted when `greet` is called with `None`
+    fn with_default_name(mut self, default_name: impl Into<String>) -> Self {
+        self.default_name = default_name.into();
+        self
+    }
+
+    /// Loads the greeting template from a file, trimming the trailing newline
+    fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, GreetError> {
+        let contents = fs::read_to_string(path)?;
+        Self::new(contents.trim_end_matches('\n'))
+    }
+
+    /// Loads the greeting template from an environment variable, trimming
+    /// the trailing newline
+    fn from_env(var: &str) -> Result<Self, GreetError> {
+        let contents =
+            env::var(var).map_err(|_| GreetError::MissingEnvVar(var.to_string()))?;
+        Self::new(contents.trim_end_matches('\n'))
+    }
+
+    /// Creates a greeting message, expanding any `/etc/issue`-style tokens
+    /// and ANSI escapes in the stored template first. Pass `strip_ansi` to
+    /// get a plain-text message suitable for logs instead of a TTY.
+    ///
+    /// `name` of `None` falls back to `default_name`; an explicit `Some("")`
+    /// is still rejected as an empty name.
+    fn greet(&self, name: Option<&str>, strip_ansi: bool) -> Result<String, GreetError> {
+        let name = match name {
+            Some("") => return Err(GreetError::EmptyName),
+            Some(name) => name,
+            None => self.default_name.as_str(),
+        };
+        let styled = Self::expand_template(&self.greeting)?;
+        let message = format!("{}, {}!", styled, name);
+        Ok(if strip_ansi {
+            Self::strip_sgr(&message)
+        } else {
+            message
+        })
+    }
+
+    /// Convenience wrapper over [`Greeter::greet`] that always strips SGR
+    /// sequences, for callers that only want a clean log line
+    fn plain(&self, name: Option<&str>) -> Result<String, GreetError> {
+        self.greet(name, true)
+    }
+
+    /// Strips SGR (`ESC [ ... m`) sequences, leaving plain text
+    fn strip_sgr(s: &str) -> String {
+        let mut out = String::with_capacity(s.len());
+        let mut chars = s.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '\u{1b}' && chars.peek() == Some(&'[') {
+                chars.next();
+                for c2 in chars.by_ref() {
+                    if c2 == 'm' {
+                        break;
+                    }
+                }
+            } else {
+                out.push(c);
+            }
         }
-        Ok(format!("{}, {}!", self.greeting, name))
+        out
+    }
+
+    /// Expands `\n \s \r \v \m` escape tokens against live `uname` output,
+    /// rewrites `\e`/`\x1b`/`\033` ANSI spellings into a real ESC (0x1b)
+    /// byte, and turns `\\` into a literal backslash, leaving text outside
+    /// these tokens untouched. Any other backslash sequence, including a
+    /// trailing lone `\`, is rejected with [`GreetError::MalformedToken`].
+    ///
+    /// All of this happens in a single left-to-right pass so that `\\` is
+    /// always matched before any multi-character token: otherwise decoding
+    /// ANSI escapes as a separate, prior pass could steal the second
+    /// backslash of a `\\` pair whenever it was itself followed by `e`,
+    /// turning a literal `\emacs` into a malformed `<ESC>macs`.
+    fn expand_template(template: &str) -> Result<String, GreetError> {
+        let mut expanded = String::with_capacity(template.len());
+        let mut chars = template.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                expanded.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('\\') => expanded.push('\\'),
+                Some('e') => expanded.push('\u{1b}'),
+                Some('x') if Self::eat_literal(&mut chars, "1b") => expanded.push('\u{1b}'),
+                Some('0') if Self::eat_literal(&mut chars, "33") => expanded.push('\u{1b}'),
+                Some('n') => expanded.push_str(Self::uname_field(&NODENAME, "-n")?),
+                Some('s') => expanded.push_str(Self::uname_field(&SYSNAME, "-s")?),
+                Some('r') => expanded.push_str(Self::uname_field(&RELEASE, "-r")?),
+                Some('v') => expanded.push_str(Self::uname_field(&VERSION, "-v")?),
+                Some('m') => expanded.push_str(Self::uname_field(&MACHINE, "-m")?),
+                Some(other) => return Err(GreetError::MalformedToken(other)),
+                None => return Err(GreetError::MalformedToken('\\')),
+            }
+        }
+        Ok(expanded)
+    }
+
+    /// Consumes `rest` from `chars` only if it matches exactly, leaving
+    /// `chars` untouched (and returning `false`) on a mismatch
+    fn eat_literal(chars: &mut std::str::Chars<'_>, rest: &str) -> bool {
+        let mut lookahead = chars.clone();
+        for expected in rest.chars() {
+            if lookahead.next() != Some(expected) {
+                return false;
+            }
+        }
+        *chars = lookahead;
+        true
+    }
+
+    /// Queries a single `uname` field (e.g. `-n` for nodename), caching the
+    /// result so a banner with several tokens (or repeated renders, as in
+    /// the TUI's redraw loop) forks `uname` at most once per field
+    fn uname_field(cache: &'static OnceLock<String>, flag: &str) -> Result<&'static str, GreetError> {
+        if let Some(value) = cache.get() {
+            return Ok(value);
+        }
+        let output = Command::new("uname").arg(flag).output()?;
+        let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok(cache.get_or_init(|| value))
     }
     
-    /// Writes greeting to a writer (for testability)
+    /// Writes the styled greeting to a writer untouched (for testability)
     fn greet_to_writer<W: Write>(
         &self,
         writer: &mut W,
-        name: &str,
+        name: Option<&str>,
     ) -> Result<(), GreetError> {
-        let message = self.greet(name)?;
+        let message = self.greet(name, false)?;
         writeln!(writer, "{}", message)?;
         Ok(())
     }
@@ -71,37 +230,79 @@ impl Default for Greeter {
     fn default() -> Self {
         Self {
             greeting: "Hello".to_string(),
+            default_name: "world".to_string(),
+            mode: tui::Mode::Greeting,
+            previous_mode: tui::Mode::Greeting,
+            buffer: tui::InputBuffer::default(),
+            session_source: PathBuf::from("/usr/share/xsessions"),
+            sessions: tui::Menu::default(),
+            users: tui::Menu::default(),
         }
     }
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
+    if env::args().nth(1).as_deref() == Some("--tui") {
+        let mut greeter = Greeter::default();
+        return Ok(tui::run(&mut greeter)?);
+    }
+
     // Simple version
     println!("Hello, World!");
     
     // Using the struct
     let greeter = Greeter::default();
-    greeter.greet_to_writer(&mut io::stdout(), "World")?;
-    
+    greeter.greet_to_writer(&mut io::stdout(), Some("World"))?;
+
     // Custom greeting with error handling
     let custom_greeter = Greeter::new("Greetings")?;
-    custom_greeter.greet_to_writer(&mut io::stdout(), "Rust Developer")?;
-    
+    custom_greeter.greet_to_writer(&mut io::stdout(), Some("Rust Developer"))?;
+
+    // Plain (ANSI-stripped) output, for logs instead of a TTY
+    println!("{}", custom_greeter.plain(Some("Rust Developer"))?);
+
     // Using iterator and functional style
     let names = vec!["Alice", "Bob", "Charlie"];
     names
         .iter()
-        .map(|name| greeter.greet(name))
+        .map(|name| greeter.greet(Some(name), false))
         .collect::<Result<Vec<_>, _>>()?
         .iter()
         .for_each(|msg| println!("{}", msg));
-    
+
+    // Falling back to the default recipient
+    println!("{}", greeter.greet(None, false)?);
+
+    // A greeter configured with its own default recipient
+    let named_greeter = Greeter::new("Welcome")?.with_default_name("crate contributor");
+    println!("{}", named_greeter.greet(None, false)?);
+
     // Demonstrate error handling
-    match greeter.greet("") {
+    match greeter.greet(Some(""), false) {
         Ok(_) => println!("This shouldn't happen"),
         Err(e) => println!("Expected error: {}", e),
     }
-    
+
+    // Loading the greeting template from a file
+    let mut greeting_path = env::temp_dir();
+    greeting_path.push("hello_greeting.txt");
+    fs::write(&greeting_path, "Bonjour\n")?;
+    let file_greeter = Greeter::from_file(&greeting_path)?;
+    println!("{}", file_greeter.greet(Some("File"), false)?);
+    fs::remove_file(&greeting_path)?;
+
+    // Loading the greeting template from an environment variable
+    env::set_var("HELLO_GREETING", "Salutations");
+    let env_greeter = Greeter::from_env("HELLO_GREETING")?;
+    println!("{}", env_greeter.greet(Some("Env"), false)?);
+    env::remove_var("HELLO_GREETING");
+
+    // Demonstrate the missing-variable error path
+    match Greeter::from_env("HELLO_GREETING_DOES_NOT_EXIST") {
+        Ok(_) => println!("This shouldn't happen"),
+        Err(e) => println!("Expected error: {}", e),
+    }
+
     Ok(())
 }
 
@@ -112,12 +313,84 @@ mod tests {
     #[test]
     fn test_greet_success() {
         let greeter = Greeter::default();
-        assert_eq!(greeter.greet("Test").unwrap(), "Hello, Test!");
+        assert_eq!(greeter.greet(Some("Test"), false).unwrap(), "Hello, Test!");
     }
-    
+
     #[test]
     fn test_greet_empty_name() {
         let greeter = Greeter::default();
-        assert!(matches!(greeter.greet(""), Err(GreetError::EmptyName)));
+        assert!(matches!(
+            greeter.greet(Some(""), false),
+            Err(GreetError::EmptyName)
+        ));
+    }
+
+    #[test]
+    fn test_greet_default_name() {
+        let greeter = Greeter::default();
+        assert_eq!(greeter.greet(None, false).unwrap(), "Hello, world!");
+
+        let custom = Greeter::new("Yo")
+            .unwrap()
+            .with_default_name("friend");
+        assert_eq!(custom.greet(None, false).unwrap(), "Yo, friend!");
+    }
+
+    #[test]
+    fn test_decode_ansi_and_strip() {
+        let greeter = Greeter::new("\\e[1mHello").unwrap();
+        let styled = greeter.greet(Some("Test"), false).unwrap();
+        assert!(styled.starts_with("\u{1b}[1mHello"));
+        assert_eq!(greeter.plain(Some("Test")).unwrap(), "Hello, Test!");
+    }
+
+    #[test]
+    fn test_literal_backslash_survives_ansi_decoding() {
+        // `\\emacs` is an escaped literal backslash followed by "emacs"; it
+        // must not be mistaken for the `\e` ANSI escape token.
+        let greeter = Greeter::new("\\\\emacs").unwrap();
+        assert_eq!(greeter.greet(Some("Test"), false).unwrap(), "\\emacs, Test!");
+    }
+
+    #[test]
+    fn test_expand_template_literal_backslash() {
+        let greeter = Greeter::new("\\\\Hello").unwrap();
+        assert_eq!(greeter.greet(Some("Test"), false).unwrap(), "\\Hello, Test!");
+    }
+
+    #[test]
+    fn test_expand_template_malformed_token() {
+        let greeter = Greeter::new("\\q").unwrap();
+        assert!(matches!(
+            greeter.greet(Some("Test"), false),
+            Err(GreetError::MalformedToken('q'))
+        ));
+    }
+
+    #[test]
+    fn test_from_file() {
+        let mut path = std::env::temp_dir();
+        path.push("greeter_from_file_test.txt");
+        fs::write(&path, "Howdy\n").unwrap();
+        let greeter = Greeter::from_file(&path).unwrap();
+        assert_eq!(greeter.greet(Some("Test"), false).unwrap(), "Howdy, Test!");
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_from_env() {
+        env::set_var("GREETER_TEST_GREETING", "Yo\n");
+        let greeter = Greeter::from_env("GREETER_TEST_GREETING").unwrap();
+        assert_eq!(greeter.greet(Some("Test"), false).unwrap(), "Yo, Test!");
+        env::remove_var("GREETER_TEST_GREETING");
+    }
+
+    #[test]
+    fn test_from_env_missing() {
+        env::remove_var("GREETER_TEST_MISSING");
+        assert!(matches!(
+            Greeter::from_env("GREETER_TEST_MISSING"),
+            Err(GreetError::MissingEnvVar(_))
+        ));
     }
 }
\ No newline at end of file
@@ -0,0 +1,243 @@
+//! Plain-terminal front-end for [`crate::Greeter`]: a mode-gated greeting
+//! display, user-entry prompt, and session-selection screen driven by
+//! line-based `stdin`/`stdout`, keeping this example dependency-free and
+//! buildable with a bare `rustc`.
+
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+use crate::Greeter;
+
+/// Which screen the TUI is currently showing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    #[default]
+    Greeting,
+    UserEntry,
+    SessionSelect,
+}
+
+/// A scrollable list of selectable items (users, sessions, ...)
+#[derive(Debug, Clone, Default)]
+pub struct Menu<T> {
+    items: Vec<T>,
+    selected: usize,
+}
+
+impl<T> Menu<T> {
+    pub fn new(items: Vec<T>) -> Self {
+        Self { items, selected: 0 }
+    }
+
+    pub fn next(&mut self) {
+        if !self.items.is_empty() {
+            self.selected = (self.selected + 1) % self.items.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.items.is_empty() {
+            self.selected = (self.selected + self.items.len() - 1) % self.items.len();
+        }
+    }
+
+    pub fn selected(&self) -> Option<&T> {
+        self.items.get(self.selected)
+    }
+
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+}
+
+/// An editable input line with a tracked byte-offset cursor
+#[derive(Debug, Clone, Default)]
+pub struct InputBuffer {
+    text: String,
+    cursor: usize,
+}
+
+impl InputBuffer {
+    pub fn insert(&mut self, c: char) {
+        self.text.insert(self.cursor, c);
+        self.cursor += c.len_utf8();
+    }
+
+    pub fn backspace(&mut self) {
+        if self.cursor == 0 {
+            return;
+        }
+        let prev = self.text[..self.cursor]
+            .char_indices()
+            .next_back()
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        self.text.remove(prev);
+        self.cursor = prev;
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.cursor = 0;
+    }
+}
+
+/// Discovers local user names from `/etc/passwd`
+pub fn discover_users() -> io::Result<Vec<String>> {
+    let contents = fs::read_to_string("/etc/passwd")?;
+    Ok(contents
+        .lines()
+        .filter_map(|line| line.split(':').next())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Discovers session names by listing every entry under `source` (e.g.
+/// `/usr/share/xsessions`, `/usr/share/wayland-sessions`) and taking its
+/// file stem, so `gnome.desktop` becomes `gnome`; entries are not filtered
+/// by extension. The names are returned sorted.
+pub fn discover_sessions(source: &Path) -> io::Result<Vec<String>> {
+    let mut sessions = Vec::new();
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        if let Some(name) = entry.path().file_stem().and_then(|s| s.to_str()) {
+            sessions.push(name.to_string());
+        }
+    }
+    sessions.sort();
+    Ok(sessions)
+}
+
+fn switch_mode(greeter: &mut Greeter, mode: Mode) {
+    greeter.previous_mode = greeter.mode;
+    greeter.mode = mode;
+}
+
+/// Renders the banner (via [`Greeter::greet_to_writer`]) plus whatever the
+/// active mode needs, surfacing a banner render failure instead of
+/// swallowing it
+fn draw<W: Write>(writer: &mut W, greeter: &Greeter) -> io::Result<()> {
+    if let Err(e) = greeter.greet_to_writer(writer, None) {
+        writeln!(writer, "[banner error: {}]", e)?;
+    }
+
+    match greeter.mode {
+        Mode::Greeting => {
+            writeln!(writer, "[u]ser  [s]ession  [q]uit")?;
+        }
+        Mode::UserEntry => {
+            writeln!(writer, "User: {}", greeter.buffer.as_str())?;
+            for user in greeter.users.items() {
+                let marker = if Some(user) == greeter.users.selected() {
+                    ">"
+                } else {
+                    " "
+                };
+                writeln!(writer, "{} {}", marker, user)?;
+            }
+            writeln!(
+                writer,
+                "(type a name, or j/k to pick a known user, empty line to confirm)"
+            )?;
+        }
+        Mode::SessionSelect => {
+            if greeter.sessions.items().is_empty() {
+                writeln!(
+                    writer,
+                    "No sessions found under {}",
+                    greeter.session_source.display()
+                )?;
+            }
+            for session in greeter.sessions.items() {
+                let marker = if Some(session) == greeter.sessions.selected() {
+                    ">"
+                } else {
+                    " "
+                };
+                writeln!(writer, "{} {}", marker, session)?;
+            }
+            writeln!(writer, "(j/k to move, empty line to confirm)")?;
+        }
+    }
+    writer.flush()
+}
+
+/// Handles one line of input, gating which actions are permitted by the
+/// greeter's current mode. Returns `true` once the user has quit.
+fn handle_line(greeter: &mut Greeter, line: &str) -> bool {
+    let line = line.trim_end_matches(['\n', '\r']);
+    match greeter.mode {
+        Mode::Greeting => match line {
+            "u" => {
+                greeter.buffer.clear();
+                switch_mode(greeter, Mode::UserEntry);
+            }
+            "s" => switch_mode(greeter, Mode::SessionSelect),
+            "q" => return true,
+            _ => {}
+        },
+        Mode::UserEntry => match line {
+            "j" => greeter.users.next(),
+            "k" => greeter.users.previous(),
+            "" => {
+                // A typed name wins over a picked one; either way it
+                // becomes the recipient for subsequent greetings.
+                let chosen = if greeter.buffer.as_str().is_empty() {
+                    greeter.users.selected().cloned()
+                } else {
+                    Some(greeter.buffer.as_str().to_string())
+                };
+                if let Some(name) = chosen {
+                    greeter.default_name = name;
+                }
+                switch_mode(greeter, greeter.previous_mode);
+            }
+            _ => {
+                for c in line.chars() {
+                    if c == '\u{8}' {
+                        greeter.buffer.backspace();
+                    } else {
+                        greeter.buffer.insert(c);
+                    }
+                }
+            }
+        },
+        Mode::SessionSelect => match line {
+            "j" => greeter.sessions.next(),
+            "k" => greeter.sessions.previous(),
+            "" => switch_mode(greeter, greeter.previous_mode),
+            _ => {}
+        },
+    }
+    false
+}
+
+/// Runs the interactive loop over `stdin`/`stdout` until the user quits
+/// from the greeting screen. Populates `greeter.sessions` by discovering
+/// entries under `greeter.session_source` and surfaces known users from
+/// `/etc/passwd` while on the user-entry screen.
+pub fn run(greeter: &mut Greeter) -> io::Result<()> {
+    greeter.sessions = Menu::new(discover_sessions(&greeter.session_source).unwrap_or_default());
+    greeter.users = Menu::new(discover_users().unwrap_or_default());
+
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+    let mut line = String::new();
+
+    loop {
+        draw(&mut stdout, greeter)?;
+
+        line.clear();
+        if stdin.lock().read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        if handle_line(greeter, &line) {
+            return Ok(());
+        }
+    }
+}